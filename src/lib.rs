@@ -1,4 +1,8 @@
 use hex::{decode, encode};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
 pub fn decode_hex(hex_str: &str) -> Result<Vec<u8>, String> {
     // Attempt to decode the hexadecimal string into a byte vector.
@@ -28,6 +32,99 @@ pub fn swap_endian_u32(num: u32) -> [u8; 4] {
     num.to_le_bytes()
 }
 
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn sha256d(data: &[u8]) -> [u8; 32] {
+    // Bitcoin hashes everything twice with SHA-256 to guard against
+    // length-extension attacks on the inner digest.
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+fn base58_encode(bytes: &[u8]) -> String {
+    // Each leading zero byte becomes a leading '1', since '1' is digit 0 in
+    // the base58 alphabet and plain division can't produce one on its own.
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Repeated long division of the big-endian byte string by 58, collecting
+    // remainders as base58 digits from least to most significant.
+    let mut input = bytes.to_vec();
+    let mut digits: Vec<u8> = Vec::new();
+    let mut start = leading_zeros;
+    while start < input.len() {
+        let mut remainder: u32 = 0;
+        for byte in input.iter_mut().skip(start) {
+            let value = (remainder << 8) + *byte as u32;
+            *byte = (value / 58) as u8;
+            remainder = value % 58;
+        }
+        digits.push(remainder as u8);
+        while start < input.len() && input[start] == 0 {
+            start += 1;
+        }
+    }
+
+    let mut result = String::with_capacity(leading_zeros + digits.len());
+    result.extend(std::iter::repeat_n('1', leading_zeros));
+    result.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| BASE58_ALPHABET[digit as usize] as char),
+    );
+    result
+}
+
+fn base58_decode(s: &str) -> Result<Vec<u8>, String> {
+    let leading_zeros = s.chars().take_while(|&c| c == '1').count();
+
+    // Accumulate base-256 digits least-significant-first by repeatedly
+    // multiplying the running value by 58 and adding the next base58 digit.
+    let mut output: Vec<u8> = Vec::new();
+    for c in s.chars() {
+        let digit = BASE58_ALPHABET
+            .iter()
+            .position(|&b| b as char == c)
+            .ok_or_else(|| "invalid base58 character".to_string())? as u32;
+
+        let mut carry = digit;
+        for byte in output.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            output.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(output.iter().rev());
+    Ok(decoded)
+}
+
+/// Encodes `payload` as Base58Check: base58 of `payload` followed by the
+/// first 4 bytes of `SHA256(SHA256(payload))`.
+pub fn base58check_encode(payload: &[u8]) -> String {
+    let checksum = sha256d(payload);
+    let mut extended = payload.to_vec();
+    extended.extend_from_slice(&checksum[..4]);
+    base58_encode(&extended)
+}
+
+/// Reverses `base58check_encode`, verifying the trailing 4-byte checksum.
+pub fn base58check_decode(s: &str) -> Result<Vec<u8>, String> {
+    let decoded = base58_decode(s)?;
+    if decoded.len() < 4 {
+        return Err("too short".to_string());
+    }
+    let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+    if sha256d(payload)[..4] != *checksum {
+        return Err("invalid checksum".to_string());
+    }
+    Ok(payload.to_vec())
+}
+
 pub fn parse_satoshis(input: &str) -> Result<u64, String> {
     // Attempt to parse the input string into a u64.
     // If parsing succeeds, return Ok(value).
@@ -37,31 +134,190 @@ pub fn parse_satoshis(input: &str) -> Result<u64, String> {
         .map_err(|_| "Invalid satoshi amount".to_string())
 }
 
+// There will never be more than 21 million BTC, so this is the ceiling every
+// `Amount` constructor enforces.
+pub const MAX_SATOSHIS: u64 = 2_100_000_000_000_000;
+
+// Checked satoshi amount. Wrapping the raw `u64` stops BTC/sat mixups and
+// silent overflow from ever reaching `Wallet`/fee code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Amount(u64);
+
+impl Amount {
+    pub fn from_sat(sats: u64) -> Result<Self, String> {
+        // Reject anything above the 21-million-BTC supply cap up front so an
+        // out-of-range `Amount` can never be constructed.
+        if sats > MAX_SATOSHIS {
+            return Err(format!(
+                "amount {} sats exceeds the 21,000,000 BTC supply cap",
+                sats
+            ));
+        }
+        Ok(Amount(sats))
+    }
+
+    pub fn from_btc(btc: f64) -> Result<Self, String> {
+        if !btc.is_finite() || btc < 0.0 {
+            return Err("invalid BTC amount".to_string());
+        }
+        // Round to the nearest sat to absorb f64 representation error.
+        Self::from_sat((btc * 100_000_000.0).round() as u64)
+    }
+
+    pub fn to_sat(self) -> u64 {
+        self.0
+    }
+
+    pub fn to_btc(self) -> f64 {
+        self.0 as f64 / 100_000_000.0
+    }
+
+    pub fn checked_add(self, other: Amount) -> Option<Amount> {
+        self.0
+            .checked_add(other.0)
+            .filter(|sats| *sats <= MAX_SATOSHIS)
+            .map(Amount)
+    }
+
+    pub fn checked_sub(self, other: Amount) -> Option<Amount> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Render as "0.00012345 BTC", matching how wallets display amounts.
+        write!(f, "{:.8} BTC", self.to_btc())
+    }
+}
+
+impl FromStr for Amount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A decimal point means decimal-BTC input; otherwise treat the
+        // string as a plain integer satoshi count.
+        if s.contains('.') {
+            let btc: f64 = s.parse().map_err(|_| "Invalid BTC amount".to_string())?;
+            Amount::from_btc(btc)
+        } else {
+            let sats: u64 = s.parse().map_err(|_| "Invalid satoshi amount".to_string())?;
+            Amount::from_sat(sats)
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)] // Derive Debug and PartialEq for easy printing and comparison in tests.
 pub enum ScriptType {
     P2PKH,
     P2WPKH,
+    P2SH,
+    P2WSH,
+    P2TR,
+    // Any other witness program: versions 2..=16, plus version 0 or 1
+    // programs whose length matches none of the variants above. Holds the
+    // version number.
+    WitnessProgram(u8),
     Unknown,
 }
 
+// Maps a witness program's leading opcode to its version number: `OP_0` is
+// version 0, and `OP_1`..`OP_16` (0x51..=0x60) are versions 1..=16. Returns
+// `None` unless the second byte is a valid push length (2..=40) and the rest
+// of the script is exactly that many bytes, per BIP141.
+pub fn witness_version(script: &[u8]) -> Option<u8> {
+    let version = match *script.first()? {
+        0x00 => 0,
+        op @ 0x51..=0x60 => op - 0x50,
+        _ => return None,
+    };
+    let push_len = *script.get(1)? as usize;
+    if !(2..=40).contains(&push_len) {
+        return None;
+    }
+    if script.len() != 2 + push_len {
+        return None;
+    }
+    Some(version)
+}
+
 pub fn classify_script(script: &[u8]) -> ScriptType {
     // Check if the script starts with the P2PKH pattern (OP_DUP OP_HASH160 OP_PUSHBYTES_20).
     // 0x76 is OP_DUP, 0xa9 is OP_HASH160, 0x14 is PUSHBYTES_20.
     if script.starts_with(&[0x76, 0xa9, 0x14]) {
-        ScriptType::P2PKH
-    // Check if the script starts with the P2WPKH pattern (OP_0 OP_PUSHBYTES_20).
-    // 0x00 is OP_0, 0x14 is PUSHBYTES_20.
-    } else if script.starts_with(&[0x00, 0x14]) {
-        ScriptType::P2WPKH
-    } else {
-        ScriptType::Unknown
+        return ScriptType::P2PKH;
+    }
+    // P2SH: OP_HASH160 <20-byte hash> OP_EQUAL (0xa9 0x14 ... 0x87), 23 bytes
+    // total. Checking the prefix/suffix alone would also match shorter
+    // scripts that skip the 20-byte hash entirely.
+    if script.len() == 23 && script.starts_with(&[0xa9, 0x14]) && script.last() == Some(&0x87) {
+        return ScriptType::P2SH;
     }
+    // Everything else standard is a witness program: `witness_version`
+    // handles the opcode-to-version mapping and push-length validation.
+    if let Some(version) = witness_version(script) {
+        let program_len = script.len() - 2;
+        return match (version, program_len) {
+            (0, 20) => ScriptType::P2WPKH,
+            (0, 32) => ScriptType::P2WSH,
+            (1, 32) => ScriptType::P2TR,
+            _ => ScriptType::WitnessProgram(version),
+        };
+    }
+    ScriptType::Unknown
 }
 
-// Outpoint tuple struct with a String for txid and u32 for vout.
+// A transaction id. Internally stored in the same little-endian byte order
+// the raw tx/block encoding uses; `Display`/`to_hex` reverse those bytes,
+// since Bitcoin always shows txids in the opposite (big-endian-looking)
+// order. Keeping the two straight in one type is cheaper than chasing the
+// byte-order bug by hand every time a txid crosses a wire/display boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Txid([u8; 32]);
+
+impl Txid {
+    pub fn from_hex(s: &str) -> Result<Self, String> {
+        // Hex strings are written in display (reversed) order, so reverse
+        // back to get the internal little-endian storage order.
+        let displayed = decode_hex(s)?;
+        let internal = to_big_endian(&displayed);
+        let bytes: [u8; 32] = internal
+            .try_into()
+            .map_err(|_| "txid must be exactly 32 bytes".to_string())?;
+        Ok(Txid(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        bytes_to_hex(&to_big_endian(&self.0))
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Txid(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl FromStr for Txid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Txid::from_hex(s)
+    }
+}
+
+impl fmt::Display for Txid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+// Outpoint tuple struct with a Txid for txid and u32 for vout.
 // I need to add necessary derive traits for cloning, equality, and debugging.
-#[derive(Debug, Clone, PartialEq)]
-pub struct Outpoint(pub String, pub u32);
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Outpoint(pub Txid, pub u32);
 
 pub fn read_pushdata(script: &[u8]) -> &[u8] {
     // Return the slice of the script starting from index 2 to the end.
@@ -70,26 +326,29 @@ pub fn read_pushdata(script: &[u8]) -> &[u8] {
 }
 
 pub trait Wallet {
-    fn balance(&self) -> u64;
+    fn balance(&self) -> Amount;
 }
 
 pub struct TestWallet {
-    pub confirmed: u64,
+    pub confirmed: Amount,
 }
 
 impl Wallet for TestWallet {
-    fn balance(&self) -> u64 {
+    fn balance(&self) -> Amount {
         // Return the confirmed balance of the wallet.
         self.confirmed
     }
 }
 
-pub fn apply_fee(balance: &mut u64, fee: u64) {
-    // Subtract the fee from the mutable balance reference.
-    *balance = balance.saturating_sub(fee); // Use saturating_sub to prevent underflow if fee > balance.
+pub fn apply_fee(balance: Amount, fee: Amount) -> Result<Amount, String> {
+    // Reject rather than silently saturate: a fee that exceeds the balance
+    // means the caller built an invalid transaction.
+    balance
+        .checked_sub(fee)
+        .ok_or_else(|| "fee exceeds balance".to_string())
 }
 
-pub fn move_txid(txid: String) -> String {
+pub fn move_txid(txid: &Txid) -> String {
     // Format the txid string for display or logging.
     format!("txid: {}", txid)
 }
@@ -117,14 +376,347 @@ impl Opcode {
 // Add necessary derive traits for debugging, cloning, and equality.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UTXO {
-    pub txid: Vec<u8>,
+    pub txid: Txid,
     pub vout: u32,
     pub value: u64,
 }
 
-pub fn consume_utxo(utxo: UTXO) -> UTXO {
-    // In this simple case, "consuming" the UTXO just means returning it.
-    // In a real application, this might involve removing it from a UTXO set
-    // or marking it as spent. For the purpose of this exercise, we just return it.
-    utxo
+// A spendable-coin store keyed by the outpoint each UTXO sits at. Replaces
+// the old `consume_utxo` toy, which had nowhere to remove a spent coin from.
+#[derive(Debug, Clone, Default)]
+pub struct UtxoSet {
+    coins: HashMap<Outpoint, UTXO>,
+}
+
+impl UtxoSet {
+    pub fn new() -> Self {
+        UtxoSet {
+            coins: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, utxo: UTXO) {
+        let outpoint = Outpoint(utxo.txid, utxo.vout);
+        self.coins.insert(outpoint, utxo);
+    }
+
+    pub fn contains(&self, outpoint: &Outpoint) -> bool {
+        self.coins.contains_key(outpoint)
+    }
+
+    /// Removes and returns the UTXO at `outpoint`, marking it spent.
+    pub fn spend(&mut self, outpoint: &Outpoint) -> Option<UTXO> {
+        self.coins.remove(outpoint)
+    }
+
+    pub fn remove(&mut self, outpoint: &Outpoint) -> Option<UTXO> {
+        self.coins.remove(outpoint)
+    }
+
+    pub fn balance(&self) -> u64 {
+        // `UTXO.value` isn't validated on insert, so a handful of
+        // individually-plausible entries could still sum past both u64 and
+        // the supply cap; saturate rather than let that overflow or escape
+        // as an out-of-range `Amount`.
+        self.coins
+            .values()
+            .fold(0u64, |total, utxo| total.saturating_add(utxo.value).min(MAX_SATOSHIS))
+    }
+
+    /// Largest-first coin selection: sort candidates by value descending and
+    /// greedily accumulate until the running total covers `target` plus an
+    /// estimated fee, returning `None` if the whole set can't cover it.
+    pub fn select_coins(&self, target: Amount) -> Option<Vec<UTXO>> {
+        const ESTIMATED_FEE_PER_INPUT: u64 = 250; // rough vbytes * sat/vbyte for a single input
+
+        let mut candidates: Vec<&UTXO> = self.coins.values().collect();
+        candidates.sort_by_key(|utxo| std::cmp::Reverse(utxo.value));
+
+        // Same reasoning as `balance()`: `UTXO.value` is an unvalidated u64,
+        // so plausible individual values can still sum past u64::MAX.
+        let mut selected = Vec::new();
+        let mut total: u64 = 0;
+        for utxo in candidates {
+            selected.push(utxo.clone());
+            total = total.saturating_add(utxo.value);
+            let estimated_fee = ESTIMATED_FEE_PER_INPUT.saturating_mul(selected.len() as u64);
+            if total >= target.to_sat().saturating_add(estimated_fee) {
+                return Some(selected);
+            }
+        }
+        None
+    }
+}
+
+// A wallet whose balance is the live sum of an actual UtxoSet, rather than
+// TestWallet's fixed `confirmed` figure.
+pub struct UtxoWallet {
+    pub utxos: UtxoSet,
+}
+
+impl Wallet for UtxoWallet {
+    fn balance(&self) -> Amount {
+        // `UtxoSet::balance` saturates at MAX_SATOSHIS, so this can't fail.
+        Amount::from_sat(self.utxos.balance()).expect("utxo set balance exceeds supply cap")
+    }
+}
+
+// CompactSize ("var-int"): the prefix byte tells you how many following
+// bytes (if any) hold the actual value, all little-endian.
+fn encode_compact_size(n: u64) -> Vec<u8> {
+    if n < 0xFD {
+        vec![n as u8]
+    } else if n <= 0xFFFF {
+        let mut out = vec![0xFD];
+        out.extend_from_slice(&(n as u16).to_le_bytes());
+        out
+    } else if n <= 0xFFFF_FFFF {
+        let mut out = vec![0xFE];
+        out.extend_from_slice(&(n as u32).to_le_bytes());
+        out
+    } else {
+        let mut out = vec![0xFF];
+        out.extend_from_slice(&n.to_le_bytes());
+        out
+    }
+}
+
+/// Returns the decoded value and the number of bytes the CompactSize took up.
+fn decode_compact_size(bytes: &[u8]) -> Result<(u64, usize), String> {
+    match *bytes.first().ok_or("unexpected end of data")? {
+        0xFD => {
+            let raw: [u8; 2] = bytes
+                .get(1..3)
+                .ok_or("unexpected end of data")?
+                .try_into()
+                .unwrap();
+            Ok((u16::from_le_bytes(raw) as u64, 3))
+        }
+        0xFE => {
+            let raw: [u8; 4] = bytes
+                .get(1..5)
+                .ok_or("unexpected end of data")?
+                .try_into()
+                .unwrap();
+            Ok((u32::from_le_bytes(raw) as u64, 5))
+        }
+        0xFF => {
+            let raw: [u8; 8] = bytes
+                .get(1..9)
+                .ok_or("unexpected end of data")?
+                .try_into()
+                .unwrap();
+            Ok((u64::from_le_bytes(raw), 9))
+        }
+        n => Ok((n as u64, 1)),
+    }
+}
+
+// Bounds-checked with `checked_add` rather than `+`: `len` comes straight off
+// the wire (e.g. a CompactSize script length), so a crafted huge value must
+// fail the slice lookup instead of overflowing `pos` and panicking.
+fn read_bytes<'a>(bytes: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8], String> {
+    let end = pos.checked_add(len).ok_or("unexpected end of data")?;
+    let slice = bytes.get(*pos..end).ok_or("unexpected end of data")?;
+    *pos = end;
+    Ok(slice)
+}
+
+fn read_u32_le(bytes: &[u8], pos: &mut usize) -> Result<u32, String> {
+    let raw: [u8; 4] = read_bytes(bytes, pos, 4)?.try_into().unwrap();
+    Ok(u32::from_le_bytes(raw))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxIn {
+    pub prev: Outpoint,
+    pub script_sig: Vec<u8>,
+    pub sequence: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxOut {
+    pub value: Amount,
+    pub script_pubkey: Vec<u8>,
+}
+
+// A minimal raw transaction: just enough of the wire format to serialize
+// what `UtxoSet`/`classify_script` already model into a broadcastable blob.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    pub version: u32,
+    pub inputs: Vec<TxIn>,
+    pub outputs: Vec<TxOut>,
+    pub locktime: u32,
+}
+
+impl Transaction {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&swap_endian_u32(self.version));
+
+        out.extend(encode_compact_size(self.inputs.len() as u64));
+        for input in &self.inputs {
+            // Txid is stored internally in little-endian order already.
+            out.extend_from_slice(input.prev.0.as_bytes());
+            out.extend_from_slice(&swap_endian_u32(input.prev.1));
+            out.extend(encode_compact_size(input.script_sig.len() as u64));
+            out.extend_from_slice(&input.script_sig);
+            out.extend_from_slice(&swap_endian_u32(input.sequence));
+        }
+
+        out.extend(encode_compact_size(self.outputs.len() as u64));
+        for output in &self.outputs {
+            out.extend_from_slice(&output.value.to_sat().to_le_bytes());
+            out.extend(encode_compact_size(output.script_pubkey.len() as u64));
+            out.extend_from_slice(&output.script_pubkey);
+        }
+
+        out.extend_from_slice(&swap_endian_u32(self.locktime));
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut pos = 0;
+        let version = read_u32_le(bytes, &mut pos)?;
+
+        let (input_count, n) = decode_compact_size(&bytes[pos..])?;
+        pos += n;
+        // `input_count` is untrusted wire data, so don't pre-allocate on it
+        // directly (a crafted huge count would abort with "capacity
+        // overflow"); each iteration's `read_bytes` already bounds-checks
+        // against the real remaining data and errors out once it's short.
+        let mut inputs = Vec::new();
+        for _ in 0..input_count {
+            let txid_bytes: [u8; 32] = read_bytes(bytes, &mut pos, 32)?.try_into().unwrap();
+            let vout = read_u32_le(bytes, &mut pos)?;
+            let (script_len, n) = decode_compact_size(&bytes[pos..])?;
+            pos += n;
+            let script_sig = read_bytes(bytes, &mut pos, script_len as usize)?.to_vec();
+            let sequence = read_u32_le(bytes, &mut pos)?;
+            inputs.push(TxIn {
+                prev: Outpoint(Txid::from_bytes(txid_bytes), vout),
+                script_sig,
+                sequence,
+            });
+        }
+
+        let (output_count, n) = decode_compact_size(&bytes[pos..])?;
+        pos += n;
+        // Same reasoning as `inputs` above: never pre-allocate on an
+        // untrusted wire count.
+        let mut outputs = Vec::new();
+        for _ in 0..output_count {
+            let value_bytes: [u8; 8] = read_bytes(bytes, &mut pos, 8)?.try_into().unwrap();
+            let value = Amount::from_sat(u64::from_le_bytes(value_bytes))?;
+            let (script_len, n) = decode_compact_size(&bytes[pos..])?;
+            pos += n;
+            let script_pubkey = read_bytes(bytes, &mut pos, script_len as usize)?.to_vec();
+            outputs.push(TxOut {
+                value,
+                script_pubkey,
+            });
+        }
+
+        let locktime = read_u32_le(bytes, &mut pos)?;
+
+        Ok(Transaction {
+            version,
+            inputs,
+            outputs,
+            locktime,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58check_round_trips() {
+        let payload = vec![0x00, 0x01, 0x02, 0x03, 0x04];
+        let encoded = base58check_encode(&payload);
+        assert_eq!(base58check_decode(&encoded).unwrap(), payload);
+    }
+
+    #[test]
+    fn base58check_decode_rejects_bad_checksum() {
+        let mut encoded = base58check_encode(&[0xde, 0xad, 0xbe, 0xef]);
+        // Flip the last character so the trailing checksum no longer matches.
+        let last = encoded.pop().unwrap();
+        encoded.push(if last == '1' { '2' } else { '1' });
+        assert_eq!(
+            base58check_decode(&encoded),
+            Err("invalid checksum".to_string())
+        );
+    }
+
+    #[test]
+    fn base58check_decode_rejects_invalid_character() {
+        assert_eq!(
+            base58check_decode("0OIl"),
+            Err("invalid base58 character".to_string())
+        );
+    }
+
+    #[test]
+    fn base58check_decode_rejects_too_short_input() {
+        // "1" decodes to a single zero byte, well under the 4-byte checksum.
+        assert_eq!(base58check_decode("1"), Err("too short".to_string()));
+    }
+
+    fn sample_transaction() -> Transaction {
+        Transaction {
+            version: 1,
+            inputs: vec![TxIn {
+                prev: Outpoint(Txid::from_bytes([7u8; 32]), 0),
+                script_sig: vec![0x01, 0x02, 0x03],
+                sequence: 0xffffffff,
+            }],
+            outputs: vec![TxOut {
+                value: Amount::from_sat(5_000_000_000).unwrap(),
+                script_pubkey: vec![0x76, 0xa9, 0x14],
+            }],
+            locktime: 0,
+        }
+    }
+
+    #[test]
+    fn transaction_round_trips_through_encode_decode() {
+        let tx = sample_transaction();
+        let decoded = Transaction::decode(&tx.encode()).unwrap();
+        assert_eq!(decoded, tx);
+    }
+
+    #[test]
+    fn transaction_decode_rejects_truncated_input() {
+        let encoded = sample_transaction().encode();
+        // Cut the blob short: drops the locktime and the last byte of the
+        // output's script_pubkey.
+        let truncated = &encoded[..encoded.len() - 5];
+        assert!(Transaction::decode(truncated).is_err());
+    }
+
+    #[test]
+    fn transaction_decode_rejects_oversized_input_count_without_panicking() {
+        // version (4 bytes) followed by a CompactSize claiming u64::MAX inputs.
+        let mut bytes = vec![0u8; 4];
+        bytes.push(0xFF);
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Transaction::decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn transaction_decode_rejects_oversized_script_length_without_panicking() {
+        // version + one input's txid/vout, then a CompactSize script_sig
+        // length of u64::MAX.
+        let mut bytes = vec![0u8; 4];
+        bytes.push(0x01); // one input
+        bytes.extend_from_slice(&[0u8; 32]); // txid
+        bytes.extend_from_slice(&[0u8; 4]); // vout
+        bytes.push(0xFF);
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Transaction::decode(&bytes).is_err());
+    }
 }